@@ -0,0 +1,18 @@
+#pragma once
+
+#include <stdint.h>
+
+// Largest delta time a single tick can report, so hitches don't produce huge steps
+#define CLOCK_MAX_DELTA_TIME 0.1
+
+// Frame Clock
+typedef struct frame_clock {
+    double start_time;  // absolute time the clock was started
+    double last_time;   // absolute time of the previous tick
+    double delta_time;  // seconds since the previous tick (clamped)
+    double total_time;  // seconds accumulated since the clock was started
+    uint64_t frame;     // number of ticks since the clock was started
+} frame_clock;
+
+void clock_start(frame_clock *clock);
+void clock_tick(frame_clock *clock);
@@ -1,9 +1,11 @@
 #pragma once
 
 #include "platform/platform.h"
+#include "clock.h"
 
 typedef struct engine_state {
     platform_state *platform;
+    frame_clock clock;
 } engine_state;
 
 void init_window();
\ No newline at end of file
@@ -23,3 +23,4 @@ typedef struct platform_state {
 void platform_init_window(platform_state *platform);
 void platform_free_internal_state(platform_state *platform);
 void platform_process_message();
+double platform_get_absolute_time();
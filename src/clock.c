@@ -0,0 +1,25 @@
+#include "clock.h"
+#include "platform/platform.h"
+
+void clock_start(frame_clock *clock){
+    clock->start_time = platform_get_absolute_time();
+    clock->last_time = clock->start_time;
+    clock->delta_time = 0;
+    clock->total_time = 0;
+    clock->frame = 0;
+}
+
+void clock_tick(frame_clock *clock){
+    double now = platform_get_absolute_time();
+    double delta = now - clock->last_time;
+
+    // clamp long frames so a stall steps the clock by at most one max frame
+    if(delta > CLOCK_MAX_DELTA_TIME){
+        delta = CLOCK_MAX_DELTA_TIME;
+    }
+
+    clock->last_time = now;
+    clock->delta_time = delta;
+    clock->total_time += delta;
+    clock->frame++;
+}
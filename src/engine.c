@@ -8,9 +8,11 @@ void init_window(){
     state.platform = malloc(sizeof(platform_state)); //allocate memory for platform state
 
     platform_init_window(state.platform);
+    clock_start(&state.clock);
 
     for (int i = 0; i < 10000000; i++){
         platform_process_message();
+        clock_tick(&state.clock);
     }
 
     platform_free_internal_state(state.platform);
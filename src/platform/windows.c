@@ -85,6 +85,13 @@ void platform_process_message(){
     }
 }
 
+double platform_get_absolute_time(){
+    LARGE_INTEGER frequency, now;
+    QueryPerformanceFrequency(&frequency);
+    QueryPerformanceCounter(&now);
+    return (double)now.QuadPart / (double)frequency.QuadPart;
+}
+
 // window procedure function
 LRESULT CALLBACK window_proc(HWND hwnd, UINT msg, WPARAM w_param, LPARAM l_param){
     switch(msg)